@@ -1,4 +1,4 @@
-use crate::utilities::image_to_tensor;
+use crate::utilities::{apply_exif_orientation, image_to_tensor};
 use base64::Engine;
 use comfy_builder_core::prelude::*;
 use pyo3::exceptions::PyValueError;
@@ -10,6 +10,10 @@ pub struct Input {
     #[placeholder = "data:image/png;base64,..."]
     #[tooltip = "Base64‑encoded image data."]
     image: String,
+
+    #[tooltip = "Rotate/flip the image according to its EXIF orientation tag."]
+    #[default = true]
+    auto_orient: bool,
 }
 
 #[derive(NodeOutput)]
@@ -41,8 +45,15 @@ impl<'a> Node<'a> for Base64ToImage {
                 ))
             })?;
 
+        let image = image::load_from_memory(&image_bytes)?;
+        let image = if input.auto_orient {
+            apply_exif_orientation(&image_bytes, image)
+        } else {
+            image
+        };
+
         Ok(Output {
-            image: image_to_tensor(image::load_from_memory(&image_bytes)?)?,
+            image: image_to_tensor(image)?,
         })
     }
 }
@@ -69,7 +80,8 @@ mod test {
             Input {
                 image:
                     "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAIAAACQd1PeAAAAD0lEQVR4AQEEAPv/AP8AAAMBAQCNHeWCAAAAAElFTkSuQmCC"
-                        .to_string()
+                        .to_string(),
+                auto_orient: true
             }
         );
 
@@ -83,7 +95,8 @@ mod test {
             Input {
                 image:
                     "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAIAAACQd1PeAAAAD0lEQVR4AQEEAPv/AP8AAAMBAQCNHeWCAAAAAElFTkSuQmCC"
-                        .to_string()
+                        .to_string(),
+                auto_orient: true
             }
         );
 
@@ -95,7 +108,8 @@ mod test {
         let output = run_node!(
             Base64ToImage,
             Input {
-                image: "invalid".to_string()
+                image: "invalid".to_string(),
+                auto_orient: true
             },
             return
         );
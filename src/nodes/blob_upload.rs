@@ -0,0 +1,140 @@
+use crate::utilities::{EncodedImageFormat as Format, tensor_to_image};
+use comfy_builder_core::candle::IndexOp;
+use comfy_builder_core::prelude::*;
+use image::ImageFormat;
+use pyo3::exceptions::PyValueError;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::error::Error;
+use std::io::Cursor;
+
+#[derive(NodeInput)]
+struct Input {
+    #[tooltip = "Image to encode and upload."]
+    image: Image<f32>,
+
+    #[tooltip = "Target image format for the uploaded blob."]
+    #[default = "webp"]
+    format: Format,
+
+    #[tooltip = "Blossom server base URL, e.g. https://blossom.example.com."]
+    #[placeholder = "https://blossom.example.com"]
+    server: String,
+
+    #[tooltip = "Optional `Authorization` header value for authenticated servers."]
+    authorization: Option<String>,
+}
+
+#[derive(NodeOutput)]
+struct Output {
+    #[tooltip = "URL of the uploaded blob."]
+    url: String,
+
+    #[tooltip = "SHA-256 hex digest of the uploaded blob."]
+    sha256: String,
+}
+
+#[derive(Deserialize)]
+struct BlobDescriptor {
+    url: String,
+}
+
+#[node(
+    category = "Rusty Nodes / Network",
+    description = "Encode an image and upload it to a Blossom-compatible blob server."
+)]
+struct BlobUpload;
+
+impl Node for BlobUpload {
+    type In = Input;
+    type Out = Output;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn execute(&self, input: Self::In) -> Result<Self::Out, Self::Error> {
+        let (bytes, sha256) = Self::encode(&input.image, input.format)?;
+        let format: ImageFormat = input.format.into();
+
+        let client = reqwest::blocking::Client::new();
+        let mut request = client
+            .put(format!("{}/upload", input.server.trim_end_matches('/')))
+            .header("Content-Type", format.to_mime_type())
+            .body(bytes);
+
+        if let Some(authorization) = input.authorization {
+            request = request.header("Authorization", authorization);
+        }
+
+        let response = request
+            .send()
+            .map_err(|error| PyValueError::new_err(format!("Upload request failed: {}", error)))?;
+
+        if !response.status().is_success() {
+            Err(PyValueError::new_err(format!(
+                "Upload failed with status {}",
+                response.status()
+            )))?
+        }
+
+        let descriptor: BlobDescriptor = response
+            .json()
+            .map_err(|error| PyValueError::new_err(format!("Could not parse upload response: {}", error)))?;
+
+        Ok(Output {
+            url: descriptor.url,
+            sha256,
+        })
+    }
+}
+
+impl BlobUpload {
+    /// Encodes `image` to `format` and returns the encoded bytes alongside their SHA-256 hex
+    /// digest. Split out from `execute` so the encoding/hashing logic is testable without a
+    /// network round-trip.
+    fn encode(image: &Image<f32>, format: Format) -> Result<(Vec<u8>, String), Box<dyn Error + Send + Sync>> {
+        let shape = image.dims();
+        let height = shape[1] as u32;
+        let width = shape[2] as u32;
+        let channels = shape[3] as u32;
+
+        let decoded = tensor_to_image(image.i(0)?, width, height, channels)?;
+
+        let format: ImageFormat = format.into();
+        let mut buffer = Cursor::new(Vec::new());
+        decoded.write_to(&mut buffer, format)?;
+        let bytes = buffer.into_inner();
+
+        let sha256 = hex::encode(Sha256::digest(&bytes));
+
+        Ok((bytes, sha256))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use comfy_builder_core::candle::{Device, Tensor};
+
+    #[test]
+    fn encodes_and_hashes_deterministically() {
+        let pixels = vec![0.0f32; 2 * 2 * 3];
+        let image = Image::from_tensor(Tensor::from_vec(pixels, (1, 2, 2, 3), &Device::Cpu).unwrap());
+
+        let (first_bytes, first_sha256) = BlobUpload::encode(&image, Format::Png).unwrap();
+        let (second_bytes, second_sha256) = BlobUpload::encode(&image, Format::Png).unwrap();
+
+        assert_eq!(first_bytes, second_bytes);
+        assert_eq!(first_sha256, second_sha256);
+        assert_eq!(first_sha256, hex::encode(Sha256::digest(&first_bytes)));
+    }
+
+    #[test]
+    fn different_formats_produce_different_bytes() {
+        let pixels = vec![0.0f32; 2 * 2 * 3];
+        let image = Image::from_tensor(Tensor::from_vec(pixels, (1, 2, 2, 3), &Device::Cpu).unwrap());
+
+        let (png_bytes, _) = BlobUpload::encode(&image, Format::Png).unwrap();
+        let (webp_bytes, _) = BlobUpload::encode(&image, Format::WebP).unwrap();
+
+        assert_ne!(png_bytes, webp_bytes);
+    }
+}
@@ -0,0 +1,319 @@
+use crate::utilities::tensor_to_image;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use comfy_builder_core::candle::IndexOp;
+use comfy_builder_core::prelude::*;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame};
+use pyo3::exceptions::PyValueError;
+use std::error::Error;
+use std::fs;
+use std::time::Duration;
+
+#[derive(Debug, Enum)]
+enum Format {
+    #[display_name = "gif"]
+    Gif,
+
+    #[display_name = "webp"]
+    WebP,
+
+    #[cfg(feature = "video")]
+    #[display_name = "mp4"]
+    Mp4,
+}
+
+#[derive(NodeInput)]
+struct Input {
+    #[tooltip = "Batch of frames to encode, shape [batch, h, w, c]."]
+    images: Image<f32>,
+
+    #[tooltip = "Frames per second."]
+    #[default = 24.0]
+    fps: f32,
+
+    #[tooltip = "Target container/codec for the animation."]
+    #[default = "gif"]
+    format: Format,
+
+    #[tooltip = "When set, the encoded clip is written to this path instead of being returned as Base64."]
+    output_path: Option<String>,
+
+    #[tooltip = "Add MIME header `data:<mime>;base64` to the result. Only applies when `output_path` is not set."]
+    #[default = true]
+    include_header: bool,
+}
+
+#[derive(NodeOutput)]
+struct Output {
+    #[tooltip = "Base64 string of the encoded clip, present when `output_path` is not set."]
+    base64: Option<String>,
+
+    #[tooltip = "Path the clip was written to, present when `output_path` is set."]
+    path: Option<String>,
+}
+
+#[node(
+    category = "Rusty Nodes / Image",
+    description = "Encode an image batch into an animated GIF, animated WebP, or MP4/H.264 clip."
+)]
+struct ImageBatchToVideo;
+
+impl Node for ImageBatchToVideo {
+    type In = Input;
+    type Out = Output;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn execute(&self, input: Self::In) -> Result<Self::Out, Self::Error> {
+        let (batch, height, width, channels) = input.images.dims4()?;
+
+        if input.fps <= 0.0 {
+            Err(PyValueError::new_err("fps must be greater than zero"))?
+        }
+
+        let frame_delay = Delay::from_saturating_duration(Duration::from_secs_f32(1.0 / input.fps));
+
+        let frames: Vec<_> = (0..batch)
+            .map(|index| {
+                tensor_to_image(
+                    input.images.i(index)?,
+                    width as u32,
+                    height as u32,
+                    channels as u32,
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        let bytes = match input.format {
+            Format::Gif => Self::encode_gif(&frames, frame_delay)?,
+            Format::WebP => Self::encode_webp(&frames, frame_delay)?,
+            #[cfg(feature = "video")]
+            Format::Mp4 => Self::encode_mp4(&frames, input.fps)?,
+        };
+
+        let mime = match input.format {
+            Format::Gif => "image/gif",
+            Format::WebP => "image/webp",
+            #[cfg(feature = "video")]
+            Format::Mp4 => "video/mp4",
+        };
+
+        if let Some(output_path) = input.output_path {
+            fs::write(&output_path, bytes)?;
+
+            Ok(Output {
+                base64: None,
+                path: Some(output_path),
+            })
+        } else {
+            let encoded = STANDARD.encode(bytes);
+            let base64 = if input.include_header {
+                format!("data:{};base64,{}", mime, encoded)
+            } else {
+                encoded
+            };
+
+            Ok(Output {
+                base64: Some(base64),
+                path: None,
+            })
+        }
+    }
+}
+
+impl ImageBatchToVideo {
+    fn encode_gif(
+        frames: &[image::DynamicImage],
+        delay: Delay,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        let mut bytes = Vec::new();
+
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            encoder.set_repeat(Repeat::Infinite)?;
+            encoder.encode_frames(
+                frames
+                    .iter()
+                    .map(|image| Frame::from_parts(image.to_rgba8(), 0, 0, delay)),
+            )?;
+        }
+
+        Ok(bytes)
+    }
+
+    fn encode_webp(
+        frames: &[image::DynamicImage],
+        delay: Delay,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        let (numerator, denominator) = delay.numer_denom_ms();
+        let frame_ms = numerator as f64 / denominator as f64;
+        let (width, height) = frames
+            .first()
+            .map(|image| (image.width(), image.height()))
+            .unwrap_or((0, 0));
+
+        let mut encoder = webp_animation::Encoder::new((width, height))?;
+
+        for (index, frame) in frames.iter().enumerate() {
+            encoder.add_frame(&frame.to_rgba8(), (index as f64 * frame_ms).round() as i32)?;
+        }
+
+        Ok(encoder.finalize((frames.len() as f64 * frame_ms).round() as i32)?)
+    }
+
+    #[cfg(feature = "video")]
+    fn encode_mp4(
+        frames: &[image::DynamicImage],
+        fps: f32,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        use ffmpeg_next as ffmpeg;
+        use std::io::Read;
+
+        ffmpeg::init()?;
+
+        let output_path = std::env::temp_dir().join(format!("{}.mp4", uuid::Uuid::new_v4()));
+        let mut output = ffmpeg::format::output(&output_path)?;
+
+        let codec =
+            ffmpeg::encoder::find(ffmpeg::codec::Id::H264).ok_or("H.264 encoder not available")?;
+        let mut stream = output.add_stream(codec)?;
+        let mut encoder = ffmpeg::codec::context::Context::new_with_codec(codec)
+            .encoder()
+            .video()?;
+
+        encoder.set_width(frames.first().map(|frame| frame.width()).unwrap_or(0));
+        encoder.set_height(frames.first().map(|frame| frame.height()).unwrap_or(0));
+        encoder.set_format(ffmpeg::format::Pixel::YUV420P);
+        encoder.set_time_base(ffmpeg::Rational(1, fps.round() as i32));
+
+        let mut encoder = encoder.open_as(codec)?;
+        stream.set_parameters(&encoder);
+
+        output.write_header()?;
+
+        let width = frames.first().map(|frame| frame.width()).unwrap_or(0);
+        let height = frames.first().map(|frame| frame.height()).unwrap_or(0);
+
+        let mut scaler = ffmpeg::software::scaling::Context::get(
+            ffmpeg::format::Pixel::RGB24,
+            width,
+            height,
+            ffmpeg::format::Pixel::YUV420P,
+            width,
+            height,
+            ffmpeg::software::scaling::Flags::BILINEAR,
+        )?;
+
+        for (index, frame) in frames.iter().enumerate() {
+            let rgb = frame.to_rgb8();
+
+            let mut rgb_frame =
+                ffmpeg::util::frame::Video::new(ffmpeg::format::Pixel::RGB24, width, height);
+            let stride = rgb_frame.stride(0);
+            let row_bytes = width as usize * 3;
+
+            for (row, chunk) in rgb.chunks_exact(row_bytes).enumerate() {
+                let start = row * stride;
+                rgb_frame.data_mut(0)[start..start + row_bytes].copy_from_slice(chunk);
+            }
+
+            let mut video_frame =
+                ffmpeg::util::frame::Video::new(ffmpeg::format::Pixel::YUV420P, width, height);
+            scaler.run(&rgb_frame, &mut video_frame)?;
+            video_frame.set_pts(Some(index as i64));
+
+            encoder.send_frame(&video_frame)?;
+
+            let mut packet = ffmpeg::Packet::empty();
+            while encoder.receive_packet(&mut packet).is_ok() {
+                packet.write_interleaved(&mut output)?;
+            }
+        }
+
+        encoder.send_eof()?;
+        output.write_trailer()?;
+
+        let mut bytes = Vec::new();
+        fs::File::open(&output_path)?.read_to_end(&mut bytes)?;
+        fs::remove_file(&output_path)?;
+
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use comfy_builder_core::candle::{Device, Tensor};
+    use comfy_builder_core::run_node;
+
+    fn batch(frames: usize) -> Image<f32> {
+        let pixels = vec![0.5f32; frames * 2 * 2 * 3];
+
+        Image::from_tensor(Tensor::from_vec(pixels, (frames, 2, 2, 3), &Device::Cpu).unwrap())
+    }
+
+    #[test]
+    fn encode_gif_produces_non_empty_bytes() {
+        let frames = vec![image::DynamicImage::new_rgb8(2, 2); 3];
+        let delay = Delay::from_saturating_duration(Duration::from_millis(100));
+
+        let bytes = ImageBatchToVideo::encode_gif(&frames, delay).unwrap();
+
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn encode_webp_produces_non_empty_bytes() {
+        let frames = vec![image::DynamicImage::new_rgb8(2, 2); 3];
+        let delay = Delay::from_saturating_duration(Duration::from_millis(100));
+
+        let bytes = ImageBatchToVideo::encode_webp(&frames, delay).unwrap();
+
+        assert!(!bytes.is_empty());
+    }
+
+    #[cfg(feature = "video")]
+    #[test]
+    fn encode_mp4_produces_non_empty_bytes() {
+        let frames = vec![image::DynamicImage::new_rgb8(2, 2); 3];
+
+        let bytes = ImageBatchToVideo::encode_mp4(&frames, 24.0).unwrap();
+
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn rejects_non_positive_fps() {
+        let output = run_node!(
+            ImageBatchToVideo,
+            Input {
+                images: batch(2),
+                fps: 0.0,
+                format: Format::Gif,
+                output_path: None,
+                include_header: true,
+            },
+            return
+        );
+
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn returns_base64_with_mime_header_by_default() {
+        let output = run_node!(
+            ImageBatchToVideo,
+            Input {
+                images: batch(2),
+                fps: 12.0,
+                format: Format::Gif,
+                output_path: None,
+                include_header: true,
+            }
+        );
+
+        assert!(output.base64.unwrap().starts_with("data:image/gif;base64,"));
+        assert!(output.path.is_none());
+    }
+}
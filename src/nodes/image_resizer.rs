@@ -1,6 +1,6 @@
 use comfy_builder_core::candle;
 use comfy_builder_core::candle::shape::ShapeWithOneHole;
-use comfy_builder_core::candle::{Device, IndexOp};
+use comfy_builder_core::candle::{Device, IndexOp, Tensor};
 use comfy_builder_core::prelude::*;
 use rayon::prelude::*;
 use resize::Pixel::{GrayF32, RGBF32};
@@ -9,7 +9,7 @@ use std::error::Error;
 use std::ops::Deref;
 
 #[derive(Debug, Default, Clone, Enum)]
-enum Interpolation {
+pub(crate) enum Interpolation {
     #[default]
     #[label = "lanczos3"]
     Lanczos3,
@@ -31,6 +31,24 @@ enum Interpolation {
 
     #[label = "gaussian"]
     Gaussian,
+
+    #[label = "bilinear"]
+    Bilinear,
+
+    #[label = "bicubic"]
+    Bicubic,
+
+    #[label = "bessel"]
+    Bessel,
+
+    #[label = "sinc"]
+    Sinc,
+
+    #[label = "blackman"]
+    Blackman,
+
+    #[label = "hanning"]
+    Hanning,
 }
 
 impl From<Interpolation> for Type {
@@ -43,10 +61,37 @@ impl From<Interpolation> for Type {
             Interpolation::Mitchell => Type::Mitchell,
             Interpolation::BSpline => Type::BSpline,
             Interpolation::Gaussian => Type::Gaussian,
+            // `resize` has no dedicated kernel for these; map onto the closest available one.
+            Interpolation::Bilinear => Type::Triangle,
+            Interpolation::Bicubic => Type::Catrom,
+            Interpolation::Bessel => Type::Lanczos3,
+            Interpolation::Sinc => Type::Lanczos3,
+            Interpolation::Blackman => Type::Lanczos3,
+            Interpolation::Hanning => Type::Triangle,
         }
     }
 }
 
+#[derive(Debug, Default, Clone, Enum)]
+pub(crate) enum Fit {
+    /// Stretch to the exact target size, ignoring aspect ratio.
+    #[default]
+    #[label = "stretch"]
+    Stretch,
+
+    /// Scale to fit inside the target box, centering the result and padding the rest.
+    #[label = "contain"]
+    Contain,
+
+    /// Scale to fill the target box, then center-crop the overflow.
+    #[label = "cover"]
+    Cover,
+
+    /// Scale to fit inside the target box, anchored top-left and padding the remainder.
+    #[label = "pad"]
+    Pad,
+}
+
 #[derive(Debug, NodeInput)]
 pub struct Input {
     width: usize,
@@ -54,6 +99,26 @@ pub struct Input {
     image: Image<f32>,
     mask: Option<Mask<f32>>,
     interpolation: Interpolation,
+
+    #[tooltip = "How to reconcile the source aspect ratio with the requested width/height."]
+    #[default = "stretch"]
+    fit: Fit,
+
+    #[tooltip = "Fill color used for letterbox/pad areas (red channel, 0-1)."]
+    #[default = 0.0]
+    fill_red: f32,
+
+    #[tooltip = "Fill color used for letterbox/pad areas (green channel, 0-1)."]
+    #[default = 0.0]
+    fill_green: f32,
+
+    #[tooltip = "Fill color used for letterbox/pad areas (blue channel, 0-1)."]
+    #[default = 0.0]
+    fill_blue: f32,
+
+    #[tooltip = "Fill alpha for letterbox/pad areas (0-1). Not applied: the resized image always has three (RGB) channels."]
+    #[default = 1.0]
+    fill_alpha: f32,
 }
 
 #[derive(NodeOutput)]
@@ -73,52 +138,120 @@ impl<'a> Node<'a> for ResizeImage {
     type Error = Box<dyn Error + Send + Sync>;
 
     fn execute(&self, input: Self::In) -> Result<Self::Out, Self::Error> {
+        let (batch, height, width, _) = input.image.dims4()?;
+
+        let (scaled_width, scaled_height) =
+            Self::scaled_dimensions(width, height, input.width, input.height, input.fit.clone());
+
         let mask = if let Some(mask) = input.mask {
-            let (batch, mask_height, mask_width) = mask.dims3()?;
+            let (mask_batch, mask_height, mask_width) = mask.dims3()?;
 
-            let mask = self.resize_parallel::<1, Mask<f32>, _, _, _>(
+            let resized = self.resize_parallel::<1, Mask<f32>, _, _, _>(
                 &mask,
-                batch,
+                mask_batch,
                 mask_width,
                 mask_height,
-                input.width,
-                input.height,
+                scaled_width,
+                scaled_height,
                 input.interpolation.clone(),
                 || GrayF32,
                 |batch, width, height, _| (batch, height, width),
             )?;
 
-            Some(mask)
+            let composed = self.compose::<1, Mask<f32>, _>(
+                &resized,
+                mask_batch,
+                scaled_width,
+                scaled_height,
+                input.width,
+                input.height,
+                input.fit.clone(),
+                [0.0, 0.0, 0.0, 0.0],
+                |batch, width, height, _| (batch, height, width),
+            )?;
+
+            Some(composed)
         } else {
             None
         };
 
-        let (batch, height, width, _) = input.image.dims4()?;
-
-        let image = self.resize_parallel::<3, Image<f32>, _, _, _>(
+        let resized = self.resize_parallel::<3, Image<f32>, _, _, _>(
             &input.image,
             batch,
             width,
             height,
-            input.width,
-            input.height,
+            scaled_width,
+            scaled_height,
             input.interpolation.clone(),
             || RGBF32,
             |batch, width, height, channels| (batch, height, width, channels),
         )?;
 
+        let fill = [
+            input.fill_red,
+            input.fill_green,
+            input.fill_blue,
+            input.fill_alpha,
+        ];
+
+        let image = self.compose::<3, Image<f32>, _>(
+            &resized,
+            batch,
+            scaled_width,
+            scaled_height,
+            input.width,
+            input.height,
+            input.fit,
+            fill,
+            |batch, width, height, channels| (batch, height, width, channels),
+        )?;
+
+        let (_, output_height, output_width, _) = image.dims4()?;
+
         Ok(Output {
             image,
             mask,
-            width: input.width,
-            height: input.height,
+            width: output_width,
+            height: output_height,
         })
     }
 }
 
 impl ResizeImage {
+    /// Computes the intermediate scaled dimensions a `fit` mode needs before cropping/padding
+    /// to the final `target_width`/`target_height`.
+    fn scaled_dimensions(
+        width: usize,
+        height: usize,
+        target_width: usize,
+        target_height: usize,
+        fit: Fit,
+    ) -> (usize, usize) {
+        match fit {
+            Fit::Stretch => (target_width, target_height),
+            Fit::Contain | Fit::Pad => {
+                let scale =
+                    (target_width as f64 / width as f64).min(target_height as f64 / height as f64);
+
+                (
+                    ((width as f64) * scale).round().max(1.0) as usize,
+                    ((height as f64) * scale).round().max(1.0) as usize,
+                )
+            }
+            Fit::Cover => {
+                let scale =
+                    (target_width as f64 / width as f64).max(target_height as f64 / height as f64);
+
+                (
+                    ((width as f64) * scale).round().max(1.0) as usize,
+                    ((height as f64) * scale).round().max(1.0) as usize,
+                )
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
-    fn resize_parallel<'a, const CHANNELS: usize, Output, Input, Format, Shape>(
+    pub(crate) fn resize_parallel<'a, const CHANNELS: usize, Output, Input, Format, Shape>(
         &self,
         image: &Input,
         batch: usize,
@@ -210,4 +343,173 @@ impl ResizeImage {
 
         Ok(output)
     }
+
+    /// Composes a scaled image/mask onto the final `target_width`/`target_height` canvas,
+    /// center-cropping (`Cover`) or padding with `fill` (`Contain`/`Pad`). `Stretch` is a no-op
+    /// since `scaled_width`/`scaled_height` already equal the target size in that case.
+    #[allow(clippy::too_many_arguments)]
+    fn compose<'a, const CHANNELS: usize, Output, Shape>(
+        &self,
+        scaled: &Output,
+        batch: usize,
+        scaled_width: usize,
+        scaled_height: usize,
+        target_width: usize,
+        target_height: usize,
+        fit: Fit,
+        fill: [f32; 4],
+        get_shape: fn(batch: usize, width: usize, height: usize, channels: usize) -> Shape,
+    ) -> Result<Output, candle::Error>
+    where
+        Output: IndexOp<usize> + TryFrom<(Vec<f32>, Shape, &'a Device), Error = candle::Error>,
+        Shape: ShapeWithOneHole,
+    {
+        if scaled_width == target_width && scaled_height == target_height {
+            let data: Vec<f32> = (0..batch)
+                .flat_map(|batch| {
+                    scaled
+                        .i(batch)
+                        .and_then(|tensor| tensor.flatten_all()?.to_vec1())
+                })
+                .flatten()
+                .collect();
+
+            return Output::try_from((
+                data,
+                get_shape(batch, target_width, target_height, CHANNELS),
+                &Device::Cpu,
+            ));
+        }
+
+        let (crop_x, crop_y, paste_x, paste_y) = match fit {
+            Fit::Cover => (
+                scaled_width.saturating_sub(target_width) / 2,
+                scaled_height.saturating_sub(target_height) / 2,
+                0,
+                0,
+            ),
+            Fit::Contain => (
+                0,
+                0,
+                target_width.saturating_sub(scaled_width) / 2,
+                target_height.saturating_sub(scaled_height) / 2,
+            ),
+            Fit::Pad | Fit::Stretch => (0, 0, 0, 0),
+        };
+
+        let mut canvas = Vec::with_capacity(batch * target_width * target_height * CHANNELS);
+
+        for batch_index in 0..batch {
+            let data: Vec<f32> = scaled.i(batch_index)?.flatten_all()?.to_vec1()?;
+
+            let mut frame = vec![0.0f32; target_width * target_height * CHANNELS];
+
+            for pixel_index in 0..(target_width * target_height) {
+                let offset = pixel_index * CHANNELS;
+
+                for channel in 0..CHANNELS {
+                    frame[offset + channel] = fill.get(channel).copied().unwrap_or(0.0);
+                }
+            }
+
+            let copy_width = scaled_width.saturating_sub(crop_x).min(target_width - paste_x);
+            let copy_height = scaled_height.saturating_sub(crop_y).min(target_height - paste_y);
+
+            for row in 0..copy_height {
+                let source_row = crop_y + row;
+                let destination_row = paste_y + row;
+
+                let source_offset = (source_row * scaled_width + crop_x) * CHANNELS;
+                let destination_offset = (destination_row * target_width + paste_x) * CHANNELS;
+
+                frame[destination_offset..destination_offset + copy_width * CHANNELS]
+                    .copy_from_slice(&data[source_offset..source_offset + copy_width * CHANNELS]);
+            }
+
+            canvas.extend_from_slice(&frame);
+        }
+
+        Output::try_from((
+            canvas,
+            get_shape(batch, target_width, target_height, CHANNELS),
+            &Device::Cpu,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use comfy_builder_core::run_node;
+
+    /// A 4x2 RGB source, solid red on the left half and solid blue on the right half, so a
+    /// crop/pad bug shows up as the wrong color (or the fill color) ending up in the output.
+    fn source() -> Image<f32> {
+        #[rustfmt::skip]
+        let pixels: Vec<f32> = vec![
+            1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+            1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+        ];
+
+        Image::from_tensor(Tensor::from_vec(pixels, (1, 2, 4, 3), &Device::Cpu).unwrap())
+    }
+
+    fn input(fit: Fit, width: usize, height: usize) -> Input {
+        Input {
+            width,
+            height,
+            image: source(),
+            mask: None,
+            interpolation: Interpolation::Point,
+            fit,
+            fill_red: 0.0,
+            fill_green: 1.0,
+            fill_blue: 0.0,
+            fill_alpha: 1.0,
+        }
+    }
+
+    #[test]
+    fn stretch_matches_exact_target_size() {
+        let output = run_node!(ResizeImage, input(Fit::Stretch, 2, 2));
+
+        assert_eq!(output.image.dims4().unwrap(), (1, 2, 2, 3));
+        assert_eq!((output.width, output.height), (2, 2));
+    }
+
+    #[test]
+    fn contain_letterboxes_without_cropping_content() {
+        let output = run_node!(ResizeImage, input(Fit::Contain, 4, 4));
+
+        assert_eq!(output.image.dims4().unwrap(), (1, 4, 4, 3));
+
+        let pixels: Vec<f32> = output.image.i(0).unwrap().flatten_all().unwrap().to_vec1().unwrap();
+
+        // Contain must not crop: the padded rows (top/bottom) are the fill color, not black.
+        assert_eq!(&pixels[0..3], &[0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn cover_crops_instead_of_leaving_the_canvas_as_fill() {
+        let output = run_node!(ResizeImage, input(Fit::Cover, 1, 2));
+
+        assert_eq!(output.image.dims4().unwrap(), (1, 2, 1, 3));
+
+        let pixels: Vec<f32> = output.image.i(0).unwrap().flatten_all().unwrap().to_vec1().unwrap();
+
+        // A 4x2 -> 1x2 cover crop keeps a real source pixel; it must not be the fill color.
+        assert_ne!(&pixels[0..3], &[0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn pad_anchors_content_top_left() {
+        let output = run_node!(ResizeImage, input(Fit::Pad, 4, 4));
+
+        assert_eq!(output.image.dims4().unwrap(), (1, 4, 4, 3));
+
+        let pixels: Vec<f32> = output.image.i(0).unwrap().flatten_all().unwrap().to_vec1().unwrap();
+
+        // Pad keeps the scaled content anchored at (0, 0); it must not be the fill color.
+        assert_ne!(&pixels[0..3], &[0.0, 1.0, 0.0]);
+    }
 }
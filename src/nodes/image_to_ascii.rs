@@ -0,0 +1,168 @@
+use crate::nodes::image_resizer::{Interpolation, ResizeImage};
+use comfy_builder_core::candle::IndexOp;
+use comfy_builder_core::prelude::*;
+use pyo3::exceptions::PyValueError;
+use resize::Pixel::RGBF32;
+use std::error::Error;
+
+const DEFAULT_RAMP: &str = " .:-=+*#%@";
+
+#[derive(NodeInput)]
+pub struct Input {
+    #[tooltip = "The image to render as ASCII art."]
+    image: Image<f32>,
+
+    #[tooltip = "Target width in characters. Row count is derived from the image aspect ratio."]
+    #[default = 100]
+    width: usize,
+
+    #[tooltip = "Characters ordered darkest-to-lightest used to render luminance."]
+    #[default = " .:-=+*#%@"]
+    ramp: String,
+
+    #[tooltip = "Invert the luminance mapping."]
+    #[default = false]
+    invert: bool,
+}
+
+#[derive(NodeOutput)]
+pub struct Output {
+    #[tooltip = "The rendered ASCII art, rows separated by `\\n`."]
+    ascii: String,
+}
+
+#[node(
+    category = "Rusty Nodes / Image",
+    description = "Render an image as multi-line ASCII art."
+)]
+pub struct ImageToAscii;
+
+impl Node for ImageToAscii {
+    type In = Input;
+    type Out = Output;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn execute(&self, input: Self::In) -> Result<Self::Out, Self::Error> {
+        let (_, height, width, _) = input.image.dims4()?;
+
+        if width == 0 || height == 0 {
+            Err(PyValueError::new_err("Image has zero width or height"))?
+        }
+
+        let ramp: Vec<char> = input.ramp.chars().collect();
+        let ramp = if ramp.is_empty() {
+            DEFAULT_RAMP.chars().collect()
+        } else {
+            ramp
+        };
+
+        let columns = input.width.max(1);
+        let rows = ((columns * height) / (width * 2)).max(1);
+
+        let resized = ResizeImage.resize_parallel::<3, Image<f32>, _, _, _>(
+            &input.image,
+            1,
+            width,
+            height,
+            columns,
+            rows,
+            Interpolation::Triangle,
+            || RGBF32,
+            |batch, width, height, channels| (batch, height, width, channels),
+        )?;
+
+        let pixels: Vec<f32> = resized.i(0)?.flatten_all()?.to_vec1()?;
+
+        // `resize_parallel::<3, ...>` always packs 3 floats/pixel regardless of the source
+        // image's channel count, so the stride here must match that const, not `dims4()`.
+        const CHANNELS: usize = 3;
+
+        let mut ascii = String::with_capacity((columns + 1) * rows);
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let offset = (row * columns + column) * CHANNELS;
+                let r = pixels[offset];
+                let g = pixels[offset + 1];
+                let b = pixels[offset + 2];
+
+                let mut luminance = 0.2126 * r + 0.7152 * g + 0.1152 * b;
+
+                if input.invert {
+                    luminance = 1.0 - luminance;
+                }
+
+                let index = ((luminance.clamp(0.0, 1.0)) * (ramp.len() - 1) as f32).round() as usize;
+
+                ascii.push(ramp[index]);
+            }
+
+            ascii.push('\n');
+        }
+
+        ascii.pop();
+
+        Ok(Output { ascii })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use comfy_builder_core::candle::{Device, Tensor};
+    use comfy_builder_core::run_node;
+
+    fn image(channels: usize) -> Image<f32> {
+        let pixels = vec![0.0f32; 2 * 2 * channels];
+
+        Image::from_tensor(Tensor::from_vec(pixels, (1, 2, 2, channels), &Device::Cpu).unwrap())
+    }
+
+    #[test]
+    fn renders_rgb_source() {
+        let output = run_node!(
+            ImageToAscii,
+            Input {
+                image: image(3),
+                width: 4,
+                ramp: DEFAULT_RAMP.to_string(),
+                invert: false,
+            }
+        );
+
+        assert_eq!(output.ascii.lines().count(), 2);
+    }
+
+    #[test]
+    fn does_not_panic_on_rgba_source() {
+        let output = run_node!(
+            ImageToAscii,
+            Input {
+                image: image(4),
+                width: 4,
+                ramp: DEFAULT_RAMP.to_string(),
+                invert: false,
+            }
+        );
+
+        assert_eq!(output.ascii.lines().count(), 2);
+    }
+
+    #[test]
+    fn falls_back_to_default_ramp_when_empty() {
+        let output = run_node!(
+            ImageToAscii,
+            Input {
+                image: image(3),
+                width: 4,
+                ramp: String::new(),
+                invert: false,
+            }
+        );
+
+        assert!(output
+            .ascii
+            .lines()
+            .all(|line| line.chars().all(|char| DEFAULT_RAMP.contains(char))));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::utilities::tensor_to_image;
+use crate::utilities::{EncodedImageFormat as Format, tensor_to_image};
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use comfy_builder_core::candle::IndexOp;
@@ -7,32 +7,6 @@ use image::ImageFormat;
 use std::error::Error;
 use std::io::Cursor;
 
-#[derive(Debug, Enum)]
-enum Format {
-    #[display_name = "webp"]
-    WebP,
-
-    #[display_name = "png"]
-    Png,
-
-    #[display_name = "jpeg"]
-    Jpeg,
-
-    #[display_name = "gif"]
-    Gif,
-}
-
-impl From<Format> for ImageFormat {
-    fn from(value: Format) -> Self {
-        match value {
-            Format::Jpeg => ImageFormat::Jpeg,
-            Format::Gif => ImageFormat::Gif,
-            Format::Png => ImageFormat::Png,
-            Format::WebP => ImageFormat::WebP,
-        }
-    }
-}
-
 #[derive(NodeInput)]
 struct Input {
     #[tooltip = "Image to encode. Alpha channel is preserved if present."]
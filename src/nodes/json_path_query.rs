@@ -0,0 +1,95 @@
+use comfy_builder_core::prelude::*;
+use serde_json::Value;
+use serde_json_path::JsonPath;
+use std::error::Error;
+
+#[derive(NodeInput)]
+pub struct Input {
+    #[tooltip = "A JSONPath expression, e.g. `$.items[*].url`."]
+    #[placeholder = "$.items[*].url"]
+    path: String,
+
+    #[tooltip = "The JSON document to query."]
+    json: String,
+}
+
+#[derive(NodeOutput)]
+pub struct Output {
+    #[tooltip = "The matched values, stringified. Empty when nothing matches."]
+    string: Vec<String>,
+}
+
+#[node(
+    category = "Rusty Nodes / Json",
+    description = "Query a JSON document with a full JSONPath expression and return all matches."
+)]
+pub struct JsonPathQuery;
+
+impl Node for JsonPathQuery {
+    type In = Input;
+    type Out = Output;
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn execute(&self, input: Self::In) -> Result<Self::Out, Self::Error> {
+        let value = serde_json::from_str::<Value>(&input.json)?;
+        let path = JsonPath::parse(&input.path)?;
+
+        Ok(Output {
+            string: path
+                .query(&value)
+                .all()
+                .into_iter()
+                .filter_map(|value| match value {
+                    Value::Null => None,
+                    Value::String(string) => Some(string.clone()),
+                    _ => Some(value.to_string()),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use comfy_builder_core::run_node;
+
+    #[test]
+    fn extracts_each_matching_value() {
+        let output = run_node!(
+            JsonPathQuery,
+            Input {
+                path: "$.items[*].url".to_string(),
+                json: r#"{"items":[{"url":"a"},{"url":"b"},{"score":0.1}]}"#.to_string(),
+            }
+        );
+
+        assert_eq!(output.string, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn returns_empty_list_when_nothing_matches() {
+        let output = run_node!(
+            JsonPathQuery,
+            Input {
+                path: "$.missing[*]".to_string(),
+                json: r#"{"items":[]}"#.to_string(),
+            }
+        );
+
+        assert!(output.string.is_empty());
+    }
+
+    #[test]
+    fn stringifies_non_string_scalars() {
+        let output = run_node!(
+            JsonPathQuery,
+            Input {
+                path: "$.items[*].score".to_string(),
+                json: r#"{"items":[{"score":0.5},{"score":1}]}"#.to_string(),
+            }
+        );
+
+        assert_eq!(output.string, vec!["0.5".to_string(), "1".to_string()]);
+    }
+}
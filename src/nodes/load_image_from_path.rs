@@ -1,4 +1,4 @@
-use crate::utilities::image_to_tensor;
+use crate::utilities::{apply_exif_orientation, image_to_tensor};
 use comfy_builder_core::prelude::*;
 use std::env::current_dir;
 use std::error::Error;
@@ -9,6 +9,10 @@ pub struct Input {
     #[tooltip = "Path to the image file relative to current working directory."]
     #[placeholder = "ComfyUI/input/my-image.jpg"]
     filename: String,
+
+    #[tooltip = "Rotate/flip the image according to its EXIF orientation tag."]
+    #[default = true]
+    auto_orient: bool,
 }
 
 #[derive(NodeOutput)]
@@ -32,8 +36,15 @@ impl Node for LoadImageFromPath {
         let path = current_dir()?.join(input.filename);
         let bytes = fs::read(&path).map_err(|error| format!("{} ({:?})", error, path))?;
 
+        let image = image::load_from_memory(bytes.as_slice())?;
+        let image = if input.auto_orient {
+            apply_exif_orientation(&bytes, image)
+        } else {
+            image
+        };
+
         Ok(Output {
-            image: image_to_tensor(image::load_from_memory(bytes.as_slice())?)?,
+            image: image_to_tensor(image)?,
         })
     }
 }
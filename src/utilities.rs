@@ -1,10 +1,38 @@
 use comfy_builder_core::candle::{Device, Tensor, WithDType};
 use comfy_builder_core::numpy::Element;
-use comfy_builder_core::prelude::Image;
+use comfy_builder_core::prelude::{Enum, Image};
 use image::{DynamicImage, ImageBuffer};
 use pyo3::exceptions::PyValueError;
 use std::error::Error;
 
+/// The image formats exposed to nodes that encode pixels, shared so each node doesn't redefine
+/// its own copy of the same `Enum`/`ImageFormat` mapping.
+#[derive(Debug, Clone, Copy, Enum)]
+pub(crate) enum EncodedImageFormat {
+    #[display_name = "webp"]
+    WebP,
+
+    #[display_name = "png"]
+    Png,
+
+    #[display_name = "jpeg"]
+    Jpeg,
+
+    #[display_name = "gif"]
+    Gif,
+}
+
+impl From<EncodedImageFormat> for image::ImageFormat {
+    fn from(value: EncodedImageFormat) -> Self {
+        match value {
+            EncodedImageFormat::Jpeg => image::ImageFormat::Jpeg,
+            EncodedImageFormat::Gif => image::ImageFormat::Gif,
+            EncodedImageFormat::Png => image::ImageFormat::Png,
+            EncodedImageFormat::WebP => image::ImageFormat::WebP,
+        }
+    }
+}
+
 pub fn tensor_to_image(
     tensor: Tensor,
     width: u32,
@@ -24,29 +52,149 @@ pub fn tensor_to_image(
 
     match channels {
         3 => Ok(DynamicImage::ImageRgb8(
-            ImageBuffer::from_raw(width, height, pixels).ok_or_else(|| PyValueError::new_err("Invalid Dimensions"))?,
+            ImageBuffer::from_raw(width, height, pixels)
+                .ok_or_else(|| PyValueError::new_err("Invalid Dimensions"))?,
         )),
         4 => Ok(DynamicImage::ImageRgba8(
-            ImageBuffer::from_raw(width, height, pixels).ok_or_else(|| PyValueError::new_err("Invalid Dimensions"))?,
+            ImageBuffer::from_raw(width, height, pixels)
+                .ok_or_else(|| PyValueError::new_err("Invalid Dimensions"))?,
         )),
         _ => unreachable!(),
     }
 }
 
-pub fn image_to_tensor<T: WithDType + Element>(image: DynamicImage) -> Result<Image<T>, Box<dyn Error + Send + Sync>> {
+pub fn image_to_tensor<T: WithDType + Element>(
+    image: DynamicImage,
+) -> Result<Image<T>, Box<dyn Error + Send + Sync>> {
     let width = image.width() as usize;
     let height = image.height() as usize;
     let channels = image.color().channel_count() as usize;
     let pixels: Vec<f32> = match channels {
+        1 => image.to_rgb32f().to_vec(),
+        2 => image.to_rgba32f().to_vec(),
         3 => image.to_rgb32f().to_vec(),
         4 => image.to_rgba32f().to_vec(),
         _ => Err(PyValueError::new_err(format!(
-            "Unexpected number of channels, expected 3 or 4 but received {}",
+            "Unexpected number of channels, expected Luma, LumaA, RGB or RGBA but received {} channels",
             channels
         )))?,
     };
 
+    let channels = if channels == 1 {
+        3
+    } else if channels == 2 {
+        4
+    } else {
+        channels
+    };
+
     let tensor = Tensor::from_vec(pixels, (1, height, width, channels), &Device::Cpu)?;
 
     Ok(Image::from_tensor(tensor))
 }
+
+/// Reads the EXIF orientation tag (if any) from the original encoded bytes and applies the
+/// matching flip/rotate so the decoded image comes out upright.
+pub fn apply_exif_orientation(bytes: &[u8], image: DynamicImage) -> DynamicImage {
+    let orientation = exif::Reader::new()
+        .read_from_container(&mut std::io::Cursor::new(bytes))
+        .ok()
+        .and_then(|exif| {
+            exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+                .cloned()
+        })
+        .and_then(|field| field.value.get_uint(0))
+        .unwrap_or(1);
+
+    apply_orientation(orientation, image)
+}
+
+/// Applies the flip/rotate combination for a raw EXIF orientation value (1-8). Split out from
+/// [`apply_exif_orientation`] so the rotation table can be exercised without a real EXIF
+/// container.
+fn apply_orientation(orientation: u32, image: DynamicImage) -> DynamicImage {
+    match orientation {
+        2 => image.fliph(),
+        3 => image.rotate180(),
+        4 => image.flipv(),
+        5 => image.rotate90().fliph(),
+        6 => image.rotate90(),
+        7 => image.rotate270().fliph(),
+        8 => image.rotate270(),
+        _ => image,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 2x1 image, left pixel red, right pixel blue, so flips/rotations are distinguishable.
+    fn source() -> DynamicImage {
+        let mut image = DynamicImage::new_rgb8(2, 1);
+        image.put_pixel(0, 0, image::Rgba([255, 0, 0, 255]));
+        image.put_pixel(1, 0, image::Rgba([0, 0, 255, 255]));
+        image
+    }
+
+    #[test]
+    fn orientation_1_is_a_no_op() {
+        let oriented = apply_orientation(1, source());
+
+        assert_eq!(oriented.get_pixel(0, 0), image::Rgba([255, 0, 0, 255]));
+        assert_eq!(oriented.get_pixel(1, 0), image::Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn orientation_2_flips_horizontally() {
+        let oriented = apply_orientation(2, source());
+
+        assert_eq!(oriented.get_pixel(0, 0), image::Rgba([0, 0, 255, 255]));
+        assert_eq!(oriented.get_pixel(1, 0), image::Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn orientation_3_rotates_180() {
+        let oriented = apply_orientation(3, source());
+
+        assert_eq!(oriented.get_pixel(0, 0), image::Rgba([0, 0, 255, 255]));
+        assert_eq!(oriented.get_pixel(1, 0), image::Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn orientation_6_rotates_90_cw() {
+        let oriented = apply_orientation(6, source());
+
+        assert_eq!(oriented.dimensions(), (1, 2));
+        assert_eq!(oriented.get_pixel(0, 0), image::Rgba([255, 0, 0, 255]));
+        assert_eq!(oriented.get_pixel(0, 1), image::Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn orientation_8_rotates_270_cw() {
+        let oriented = apply_orientation(8, source());
+
+        assert_eq!(oriented.dimensions(), (1, 2));
+        assert_eq!(oriented.get_pixel(0, 0), image::Rgba([0, 0, 255, 255]));
+        assert_eq!(oriented.get_pixel(0, 1), image::Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn image_to_tensor_supports_grayscale() {
+        let image = DynamicImage::ImageLuma8(ImageBuffer::from_raw(2, 1, vec![10, 20]).unwrap());
+
+        let tensor = image_to_tensor::<f32>(image).unwrap();
+
+        assert_eq!(tensor.dims(), [1, 1, 2, 3]);
+    }
+
+    #[test]
+    fn image_to_tensor_supports_luma_alpha() {
+        let image =
+            DynamicImage::ImageLumaA8(ImageBuffer::from_raw(2, 1, vec![10, 255, 20, 128]).unwrap());
+
+        let tensor = image_to_tensor::<f32>(image).unwrap();
+
+        assert_eq!(tensor.dims(), [1, 1, 2, 4]);
+    }
+}